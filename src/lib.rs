@@ -1,10 +1,276 @@
 #![allow(unnameable_test_items)]
 
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 use zbus::{xml::Node, zvariant::Signature, Error::InterfaceNotFound, Error::MissingParameter};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+/// Environment variable naming the directory scanned for introspection XML files by
+/// the path-less lookup functions (e.g. [`get_signal_body_type`]).
+pub const XML_DIR_ENV_VAR: &str = "ZBUS_XML_PATH";
+
+/// Directory scanned for introspection XML files when `XML_DIR_ENV_VAR` is unset.
+pub const DEFAULT_XML_DIR: &str = "xml";
+
+/// Resolves the directory to scan for introspection XML files, from `XML_DIR_ENV_VAR`
+/// or falling back to `DEFAULT_XML_DIR`.
+fn xml_dir() -> PathBuf {
+    std::env::var(XML_DIR_ENV_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_XML_DIR))
+}
+
+/// Scans `dir` for `*.xml` files and returns a map of interface name to the file that
+/// defines it.
+///
+/// Every `*.xml` file in `dir` is parsed once to build the index; the map holds file paths
+/// rather than parsed interfaces, so later lookups always see the file's current
+/// contents. Files are visited in sorted-by-name order, so if more than one file
+/// defines the same interface, the one that sorts last wins deterministically
+/// (`std::fs::read_dir`'s own order is filesystem-dependent and not sorted).
+fn index_interfaces_in_dir(dir: &Path) -> Result<HashMap<String, PathBuf>> {
+    let mut index = HashMap::new();
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::result::Result<_, _>>()?;
+    paths.sort();
+
+    for path in paths {
+        if path.extension().and_then(|ext| ext.to_str()) != Some("xml") {
+            continue;
+        }
+
+        let xml = std::fs::read_to_string(&path)?;
+        let node = Node::from_str(&xml)?;
+        for interface in node.interfaces() {
+            index.insert(interface.name().to_owned(), path.clone());
+        }
+    }
+
+    Ok(index)
+}
+
+/// Finds the XML file in [`xml_dir`] that defines `interface_name`.
+fn xml_path_for_interface(interface_name: &str) -> Result<PathBuf> {
+    let index = index_interfaces_in_dir(&xml_dir())?;
+    index
+        .get(interface_name)
+        .cloned()
+        .ok_or_else(|| InterfaceNotFound.into())
+}
+
+/// A D-Bus introspection document, parsed once and kept around so that repeated
+/// signature lookups against it do not re-read and re-parse the file each time.
+///
+/// Every public function in this crate that takes an `xml_path` (e.g.
+/// [`get_signature_of_signal_body_type`]) is a thin wrapper that parses a fresh
+/// `XmlMatcher` and immediately discards it; for crates that run many lockstep
+/// signature checks against the same file, constructing one `XmlMatcher` up front
+/// and reusing it turns O(checks × file size) parsing into O(file size).
+///
+/// # Examples
+///
+/// ```rust
+/// use zbus::zvariant::Type;
+/// use atspi::cache::CacheItem;
+/// use zbus_xml_match::XmlMatcher;
+///
+/// let matcher = XmlMatcher::from_path(std::path::PathBuf::from("xml/Cache.xml")).unwrap();
+/// let signature = matcher
+///     .signal_body_type("org.a11y.atspi.Cache", "AddAccessible", Some("nodeAdded"))
+///     .unwrap();
+/// assert_eq!(signature, CacheItem::signature());
+/// ```
+pub struct XmlMatcher {
+    node: Node,
+}
+
+impl XmlMatcher {
+    /// Reads and parses the introspection XML at `xml_path`.
+    pub fn from_path(xml_path: PathBuf) -> Result<Self> {
+        let xml = std::fs::read_to_string(xml_path)?;
+        let node = Node::from_str(&xml)?;
+        Ok(Self { node })
+    }
+
+    /// Mirrors [`get_signature_of_signal_body_type`].
+    pub fn signal_body_type<'a>(
+        &self,
+        interface_name: &str,
+        member_name: &str,
+        kind: Option<&'a str>,
+    ) -> Result<Signature<'a>> {
+        let interfaces = self.node.interfaces();
+        let interface = interfaces
+            .iter()
+            .find(|iface| iface.name() == interface_name)
+            .ok_or(InterfaceNotFound)?;
+
+        let signals = interface.signals();
+        let signal = signals
+            .iter()
+            .find(|signal| signal.name() == member_name)
+            .ok_or(MissingParameter("no {member_name} found in {signals:?}"))?;
+
+        let args = signal.args();
+        let arg = args
+            .iter()
+            .find(|arg| arg.name() == kind)
+            .ok_or(MissingParameter("no {kind} found in {args:?}"))?;
+
+        let signature = arg.ty().to_owned();
+
+        Ok(Signature::from_string_unchecked(signature))
+    }
+
+    /// Mirrors [`get_signature_of_method_return_type_from_xml`].
+    pub fn method_return_type<'a>(
+        &self,
+        interface_name: &str,
+        member_name: &str,
+    ) -> Result<Signature<'a>> {
+        let interfaces = self.node.interfaces();
+        let interface = interfaces
+            .iter()
+            .find(|iface| iface.name() == interface_name)
+            .ok_or(InterfaceNotFound)?;
+
+        let methods = interface.methods();
+        let method = methods
+            .iter()
+            .find(|method| method.name() == member_name)
+            .ok_or(MissingParameter("no {member_name} found in {methods:?}"))?;
+
+        let args = method.args();
+        let arg = args
+            .iter()
+            .find(|arg| arg.direction() == Some("out"))
+            .ok_or(MissingParameter(
+                "no argument with 'out' direction in {args:?}",
+            ))?;
+
+        let signature = arg.ty().to_owned();
+
+        Ok(Signature::from_string_unchecked(signature))
+    }
+
+    /// Mirrors [`get_signature_of_method_return_types_from_xml`].
+    pub fn method_return_types<'a>(
+        &self,
+        interface_name: &str,
+        member_name: &str,
+    ) -> Result<Signature<'a>> {
+        self.method_args_by_direction(interface_name, member_name, "out")
+    }
+
+    /// Mirrors [`get_signature_of_method_args_from_xml`].
+    pub fn method_args<'a>(
+        &self,
+        interface_name: &str,
+        member_name: &str,
+    ) -> Result<Signature<'a>> {
+        self.method_args_by_direction(interface_name, member_name, "in")
+    }
+
+    /// Shared implementation behind [`XmlMatcher::method_return_types`] and
+    /// [`XmlMatcher::method_args`]: collects every argument of `method_name` whose
+    /// `direction` matches `direction`, in document order. A single matching argument
+    /// is returned bare, matching zbus body-type conventions; more than one is wrapped
+    /// into a tuple signature `(...)`.
+    fn method_args_by_direction<'a>(
+        &self,
+        interface_name: &str,
+        method_name: &str,
+        direction: &str,
+    ) -> Result<Signature<'a>> {
+        let interfaces = self.node.interfaces();
+        let interface = interfaces
+            .iter()
+            .find(|iface| iface.name() == interface_name)
+            .ok_or(InterfaceNotFound)?;
+
+        let methods = interface.methods();
+        let method = methods
+            .iter()
+            .find(|method| method.name() == method_name)
+            .ok_or(MissingParameter("no {method_name} found in {methods:?}"))?;
+
+        let types: Vec<&str> = method
+            .args()
+            .iter()
+            .filter(|arg| arg.direction() == Some(direction))
+            .map(|arg| arg.ty())
+            .collect();
+
+        let signature = match types.as_slice() {
+            [] => String::new(),
+            [single] => single.to_string(),
+            types => format!("({})", types.concat()),
+        };
+
+        Ok(Signature::from_string_unchecked(signature))
+    }
+
+    /// Mirrors [`get_signature_of_property_type_from_xml`].
+    pub fn property_type<'a>(
+        &self,
+        interface_name: &str,
+        property_name: &str,
+    ) -> Result<Signature<'a>> {
+        let interfaces = self.node.interfaces();
+        let interface = interfaces
+            .iter()
+            .find(|iface| iface.name() == interface_name)
+            .ok_or(InterfaceNotFound)?;
+
+        let properties = interface.properties();
+        let property = properties
+            .iter()
+            .find(|property| property.name() == property_name)
+            .ok_or(MissingParameter(
+                "no {property_name} found in {properties:?}",
+            ))?;
+
+        let signature = property.ty().to_owned();
+
+        Ok(Signature::from_string_unchecked(signature))
+    }
+
+    /// Mirrors [`get_signature_of_atspi_event_from_xml`].
+    pub fn atspi_event_signature<'a>(
+        &self,
+        interface_name: &str,
+        member_name: &str,
+    ) -> Result<Signature<'a>> {
+        let interfaces = self.node.interfaces();
+        let interface = interfaces
+            .iter()
+            .find(|iface| iface.name() == interface_name)
+            .ok_or(InterfaceNotFound)?;
+
+        let signals = interface.signals();
+        let signal = signals
+            .iter()
+            .find(|signal| signal.name() == member_name)
+            .ok_or(MissingParameter("no {member_name} found in {signals:?}"))?;
+
+        let args = signal.args();
+        let signature = args.iter().map(|arg| arg.ty()).collect::<String>();
+
+        // Returned bare (not wrapped in a top-level tuple): the Rust body type this is
+        // checked against is usually a struct, whose `Type::signature()` adds its own
+        // `(...)` wrapper, so wrapping here too would only work for exactly one level of
+        // struct nesting. Compare with `signatures_are_equal`/`assert_signatures_eq!`
+        // instead of `==`/`assert_eq!`.
+        Ok(Signature::from_string_unchecked(signature))
+    }
+}
+
 /// Gets the signature of a signal's return type from XML.
 ///
 /// Retrieval of signatures from the XML protocol definitions allows crates to verify if  
@@ -33,32 +299,7 @@ pub fn get_signature_of_signal_body_type<'a>(
     member_name: &'a str,
     kind: Option<&'a str>,
 ) -> Result<Signature<'a>> {
-    let xml = std::fs::read_to_string(xml_path)?;
-    let node = Node::from_str(&xml)?;
-    let interfaces = node.interfaces();
-    let interface = interfaces
-        .iter()
-        .find(|iface| iface.name() == interface_name)
-        .ok_or(InterfaceNotFound)?;
-
-    let signals = interface.signals();
-    let signal = signals
-        .iter()
-        .find(|signal| signal.name() == member_name)
-        .ok_or(MissingParameter("no {member_name} found in {signals:?}"))?;
-
-    let args = signal.args();
-    let arg = args
-        .iter()
-        .find(|arg| arg.name() == kind)
-        .ok_or(zbus::Error::MissingParameter("no {kind} found in {args:?}"))?;
-
-    let signature = arg.ty().to_owned();
-
-    // If the protocol definition does not provide a valid signature, then our problems are of different order.
-    let signature = Signature::from_string_unchecked(signature);
-
-    Ok(signature)
+    XmlMatcher::from_path(xml_path)?.signal_body_type(interface_name, member_name, kind)
 }
 
 /// Gets the signature of a method's return type from XML.
@@ -87,40 +328,157 @@ pub fn get_signature_of_method_return_type_from_xml<'a>(
     interface_name: &str,
     member_name: &str,
 ) -> Result<Signature<'a>> {
-    let xml = std::fs::read_to_string(xml_path)?;
-    let node = Node::from_str(&xml)?;
-    let interfaces = node.interfaces();
-    let interface = interfaces
-        .iter()
-        .find(|iface| iface.name() == interface_name)
-        .ok_or(InterfaceNotFound)?;
-
-    let methods = interface.methods();
-    let method = methods
-        .iter()
-        .find(|method| method.name() == member_name)
-        .ok_or(MissingParameter("no {member_name} found in {methods:?}"))?;
-
-    let args = method.args();
-    let arg = args
-        .iter()
-        .find(|arg| arg.direction() == Some("out"))
-        .ok_or(MissingParameter(
-            "no argument with 'out' direction in {args:?}",
-        ))?;
-
-    let signature = arg.ty().to_owned();
-
-    // If the protocol definition does not provide a valid signature, then our problems are of different order.
-    let signature = Signature::from_string_unchecked(signature);
-
-    Ok(signature)
+    XmlMatcher::from_path(xml_path)?.method_return_type(interface_name, member_name)
+}
+
+/// Gets the signature of all of a method's `"out"` arguments from XML, in document order.
+///
+/// Unlike [`get_signature_of_method_return_type_from_xml`], which only looks at the first
+/// `"out"` argument, this collects every one of them. A single out-arg is returned bare,
+/// matching zbus body-type conventions; more than one is wrapped into a tuple signature
+/// `(...)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use zbus::zvariant::Type;
+/// use atspi::Role;
+/// use zbus_xml_match::get_signature_of_method_return_types_from_xml;
+///
+/// let xml = std::path::PathBuf::from("xml/Accessible.xml");
+/// let interface_name = "org.a11y.atspi.Accessible";
+/// let member_name = "GetRole";
+///
+/// let signature = get_signature_of_method_return_types_from_xml(xml, interface_name, member_name).unwrap();
+/// assert_eq!(signature, Role::signature());
+/// ```
+pub fn get_signature_of_method_return_types_from_xml<'a>(
+    xml_path: PathBuf,
+    interface_name: &str,
+    member_name: &str,
+) -> Result<Signature<'a>> {
+    XmlMatcher::from_path(xml_path)?.method_return_types(interface_name, member_name)
+}
+
+/// Gets the signature of all of a method's `"in"` arguments from XML, in document order.
+///
+/// A single in-arg is returned bare, matching zbus body-type conventions; more than one
+/// is wrapped into a tuple signature `(...)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use zbus_xml_match::get_signature_of_method_args_from_xml;
+///
+/// let xml = std::path::PathBuf::from("xml/Accessible.xml");
+/// let interface_name = "org.a11y.atspi.Accessible";
+/// let member_name = "GetChildAtIndex";
+///
+/// let signature = get_signature_of_method_args_from_xml(xml, interface_name, member_name).unwrap();
+/// ```
+pub fn get_signature_of_method_args_from_xml<'a>(
+    xml_path: PathBuf,
+    interface_name: &str,
+    member_name: &str,
+) -> Result<Signature<'a>> {
+    XmlMatcher::from_path(xml_path)?.method_args(interface_name, member_name)
+}
+
+/// Gets the signature of a property's type from XML.
+///
+/// Retrieval of signatures from the XML protocol definitions allows crates to verify if
+/// the property's type and the representing type in the Rust code are the same.
+///
+/// Verification might look like this:
+///
+/// # Examples
+///
+/// ```rust
+/// use zbus::zvariant::Type;
+/// use zbus_xml_match::get_signature_of_property_type_from_xml;
+///
+/// let xml = std::path::PathBuf::from("xml/Accessible.xml");
+/// let interface_name = "org.a11y.atspi.Accessible";
+/// let property_name = "ChildCount";
+///
+/// let signature = get_signature_of_property_type_from_xml(xml, interface_name, property_name).unwrap();
+/// assert_eq!(signature, i32::signature());
+/// ```
+pub fn get_signature_of_property_type_from_xml<'a>(
+    xml_path: PathBuf,
+    interface_name: &str,
+    property_name: &str,
+) -> Result<Signature<'a>> {
+    XmlMatcher::from_path(xml_path)?.property_type(interface_name, property_name)
+}
+
+/// Compares two D-Bus signatures for equality, treating them as equal if they are
+/// identical or become identical after stripping one matched outermost `(...)`/`{...}`
+/// wrapper from either side.
+///
+/// zbus represents a body type's signature as a single top-level tuple `(...)` matching
+/// the Rust struct it deserializes into, while the XML protocol definition only lists the
+/// bare concatenation of each argument's signature (e.g. `siiv...`). This lets the two
+/// forms compare equal without the caller having to manually add or strip parens, which
+/// only works for exactly one level of struct wrapping.
+pub fn signatures_are_equal(a: &str, b: &str) -> bool {
+    a == b || strip_outer_wrapper(a) == b || a == strip_outer_wrapper(b)
+}
+
+/// Strips a leading/trailing `(...)`/`{...}` pair from `signature`, but only when the
+/// opening bracket at index 0 has its matching close at the final index - so a wrapper
+/// that merely prefixes the string is left alone.
+fn strip_outer_wrapper(signature: &str) -> &str {
+    let open = match signature.chars().next() {
+        Some(open @ ('(' | '{')) => open,
+        _ => return signature,
+    };
+    let close = if open == '(' { ')' } else { '}' };
+
+    let mut depth = 0;
+    for (index, ch) in signature.char_indices() {
+        match ch {
+            '(' | '{' => depth += 1,
+            ')' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return if ch == close && index == signature.len() - 1 {
+                        &signature[1..index]
+                    } else {
+                        signature
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+
+    signature
+}
+
+/// Asserts that two D-Bus signatures are equal per [`signatures_are_equal`], panicking
+/// with both values shown on mismatch like `assert_eq!`.
+#[macro_export]
+macro_rules! assert_signatures_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        assert!(
+            $crate::signatures_are_equal(left.as_str(), right.as_str()),
+            "signature mismatch: `{:?}` vs `{:?}`",
+            left,
+            right,
+        );
+    }};
 }
 
 /// Constructs the signature of an AT-SPI2 event from the signal's arguments in XML.
 ///
 /// Retrieval of signatures from the XML protocol definitions allows crates to verify if
-/// the signal's body type and the representing type in the Rust code are the same.
+/// the signal's body type and the representing type in the Rust code are the same. The
+/// signature returned here is the bare concatenation of the signal's argument types, with
+/// no top-level tuple wrapper added, since the Rust body type is not always a single
+/// top-level struct; compare it with [`signatures_are_equal`]/`assert_signatures_eq!`
+/// rather than `==`/`assert_eq!`.
 ///
 /// Verification might look like this:
 ///
@@ -129,45 +487,125 @@ pub fn get_signature_of_method_return_type_from_xml<'a>(
 /// ```rust
 /// use zbus::zvariant::Type;
 /// use atspi::events::EventBodyOwned;
-/// use zbus_xml_match::get_signature_of_atspi_event_from_xml;
+/// use zbus_xml_match::{assert_signatures_eq, get_signature_of_atspi_event_from_xml};
 ///
 /// let xml = std::path::PathBuf::from("xml/Event.xml");
 /// let interface_name = "org.a11y.atspi.Event.Object";
 /// let member_name = "StateChanged";
 ///
 /// let signature = get_signature_of_atspi_event_from_xml(xml, interface_name, member_name).unwrap();
-/// assert_eq!(signature, EventBodyOwned::signature());
+/// assert_signatures_eq!(signature, EventBodyOwned::signature());
 /// ```
 pub fn get_signature_of_atspi_event_from_xml<'a>(
     xml_path: PathBuf,
     interface_name: &'a str,
     member_name: &'a str,
 ) -> Result<Signature<'a>> {
-    let xml = std::fs::read_to_string(xml_path)?;
-    let node = Node::from_str(&xml)?;
-    let interfaces = node.interfaces();
-    let interface = interfaces
-        .iter()
-        .find(|iface| iface.name() == interface_name)
-        .ok_or(InterfaceNotFound)?;
-
-    let signals = interface.signals();
-    let method = signals
-        .iter()
-        .find(|signal| signal.name() == member_name)
-        .ok_or(MissingParameter("no {member_name} found in {signals:?}"))?;
-
-    let args = method.args();
-    let mut signature = args.into_iter().map(|arg| arg.ty()).collect::<String>();
-
-    // Demarshall the signature into a rust struct signature.
-    signature.insert(0, '(');
-    signature.push(')');
-
-    // If the protocol definition does not provide a valid signature, then our problems are of different order.
-    let signature = Signature::from_string_unchecked(signature);
-
-    Ok(signature)
+    XmlMatcher::from_path(xml_path)?.atspi_event_signature(interface_name, member_name)
+}
+
+/// Like [`get_signature_of_signal_body_type`], but locates the XML file defining
+/// `interface_name` automatically instead of requiring the caller to name it.
+///
+/// The directory scanned is named by the `ZBUS_XML_PATH` environment variable,
+/// falling back to `"xml"` when unset. This lets callers validate against a whole
+/// `xml/` tree without hardcoding which file holds which interface.
+///
+/// # Examples
+///
+/// ```rust
+/// use zbus::zvariant::Type;
+/// use atspi::cache::CacheItem;
+/// use zbus_xml_match::get_signal_body_type;
+///
+/// let interface_name = "org.a11y.atspi.Cache";
+/// let member_name = "AddAccessible";
+/// let kind = Some("nodeAdded");
+///
+/// let signature = get_signal_body_type(interface_name, member_name, kind).unwrap();
+/// assert_eq!(signature, CacheItem::signature());
+/// ```
+pub fn get_signal_body_type<'a>(
+    interface_name: &'a str,
+    member_name: &'a str,
+    kind: Option<&'a str>,
+) -> Result<Signature<'a>> {
+    let xml_path = xml_path_for_interface(interface_name)?;
+    get_signature_of_signal_body_type(xml_path, interface_name, member_name, kind)
+}
+
+/// Like [`get_signature_of_method_return_type_from_xml`], but locates the XML file
+/// defining `interface_name` automatically instead of requiring the caller to name it.
+///
+/// The directory scanned is named by the `ZBUS_XML_PATH` environment variable,
+/// falling back to `"xml"` when unset.
+///
+/// # Examples
+///
+/// ```rust
+/// use zbus::zvariant::Type;
+/// use atspi::Role;
+/// use zbus_xml_match::get_method_return_type;
+///
+/// let interface_name = "org.a11y.atspi.Accessible";
+/// let member_name = "GetRole";
+///
+/// let signature = get_method_return_type(interface_name, member_name).unwrap();
+/// assert_eq!(signature, Role::signature());
+/// ```
+pub fn get_method_return_type<'a>(
+    interface_name: &str,
+    member_name: &str,
+) -> Result<Signature<'a>> {
+    let xml_path = xml_path_for_interface(interface_name)?;
+    get_signature_of_method_return_type_from_xml(xml_path, interface_name, member_name)
+}
+
+/// Like [`get_signature_of_atspi_event_from_xml`], but locates the XML file defining
+/// `interface_name` automatically instead of requiring the caller to name it.
+///
+/// The directory scanned is named by the `ZBUS_XML_PATH` environment variable,
+/// falling back to `"xml"` when unset.
+pub fn get_atspi_event_signature<'a>(
+    interface_name: &'a str,
+    member_name: &'a str,
+) -> Result<Signature<'a>> {
+    let xml_path = xml_path_for_interface(interface_name)?;
+    get_signature_of_atspi_event_from_xml(xml_path, interface_name, member_name)
+}
+
+/// Searches every XML file in [`xml_dir`] for a signal called `member_name`, for
+/// callers that do not know which interface defines it.
+///
+/// The directory scanned is named by the `ZBUS_XML_PATH` environment variable,
+/// falling back to `"xml"` when unset. If more than one interface defines a signal
+/// with this name, which one is returned is unspecified.
+pub fn find_signal_body_type_by_member<'a>(
+    member_name: &'a str,
+    kind: Option<&'a str>,
+) -> Result<Signature<'a>> {
+    for entry in std::fs::read_dir(xml_dir())? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("xml") {
+            continue;
+        }
+
+        let xml = std::fs::read_to_string(&path)?;
+        let node = Node::from_str(&xml)?;
+        for interface in node.interfaces() {
+            let Some(signal) = interface.signals().iter().find(|s| s.name() == member_name)
+            else {
+                continue;
+            };
+            let Some(arg) = signal.args().iter().find(|arg| arg.name() == kind) else {
+                continue;
+            };
+
+            return Ok(Signature::from_string_unchecked(arg.ty().to_owned()));
+        }
+    }
+
+    Err(MissingParameter("no signal named {member_name} found in any interface").into())
 }
 
 /// Expands to a test function that checks if the signature of an AT-SPI2 event signal's aggregated argument types match
@@ -195,7 +633,7 @@ macro_rules! test_atspi_event_signature_and_type_match {
             let signature =
                 get_signature_of_atspi_event_from_xml(xml, interface_name, member_name).unwrap();
 
-            assert_eq!(<$type as Type>::signature(), signature);
+            $crate::assert_signatures_eq!(<$type as Type>::signature(), signature);
         }
     };
 }
@@ -203,6 +641,7 @@ macro_rules! test_atspi_event_signature_and_type_match {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_signatures_eq;
     use crate::get_signature_of_atspi_event_from_xml;
     use crate::get_signature_of_signal_body_type;
     use crate::test_atspi_event_signature_and_type_match;
@@ -237,6 +676,19 @@ mod test {
         assert_eq!(signature, Role::signature());
     }
 
+    #[test]
+    fn test_get_signature_of_property_type_child_count() {
+        use crate::get_signature_of_property_type_from_xml;
+
+        let xml = PathBuf::from("xml/Accessible.xml");
+        let interface_name = "org.a11y.atspi.Accessible";
+        let property_name = "ChildCount";
+
+        let signature =
+            get_signature_of_property_type_from_xml(xml, interface_name, property_name).unwrap();
+        assert_eq!(signature, i32::signature());
+    }
+
     #[test]
     fn test_get_signature_of_cache_remove_accessible() {
         let xml = PathBuf::from("xml/Cache.xml");
@@ -249,6 +701,139 @@ mod test {
         assert_eq!(signature, Accessible::signature());
     }
 
+    #[test]
+    fn test_xml_matcher_reuses_parsed_document_across_queries() {
+        let matcher = XmlMatcher::from_path(PathBuf::from("xml/Cache.xml")).unwrap();
+
+        let signature = matcher
+            .signal_body_type("org.a11y.atspi.Cache", "AddAccessible", Some("nodeAdded"))
+            .unwrap();
+        assert_eq!(signature, CacheItem::signature());
+
+        let signature = matcher
+            .signal_body_type("org.a11y.atspi.Cache", "RemoveAccessible", Some("nodeRemoved"))
+            .unwrap();
+        assert_eq!(signature, Accessible::signature());
+    }
+
+    #[test]
+    fn test_method_args_signature_wrapping_by_arg_count() {
+        let dir = std::env::temp_dir().join("zbus_xml_match_test_no_out_args");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let xml_path = dir.join("Void.xml");
+        std::fs::write(
+            &xml_path,
+            r#"<node>
+  <interface name="org.example.Void">
+    <method name="DoThing">
+      <arg name="input" type="s" direction="in"/>
+    </method>
+    <method name="DoManyThings">
+      <arg name="first_in" type="x" direction="in"/>
+      <arg name="second_in" type="y" direction="in"/>
+      <arg name="first_out" type="i" direction="out"/>
+      <arg name="second_out" type="u" direction="out"/>
+    </method>
+  </interface>
+</node>"#,
+        )
+        .unwrap();
+
+        let return_types = get_signature_of_method_return_types_from_xml(
+            xml_path.clone(),
+            "org.example.Void",
+            "DoThing",
+        )
+        .unwrap();
+        assert_eq!(return_types.as_str(), "");
+
+        let args = get_signature_of_method_args_from_xml(
+            xml_path.clone(),
+            "org.example.Void",
+            "DoThing",
+        )
+        .unwrap();
+        assert_eq!(args.as_str(), "s");
+
+        let return_types = get_signature_of_method_return_types_from_xml(
+            xml_path.clone(),
+            "org.example.Void",
+            "DoManyThings",
+        )
+        .unwrap();
+        assert_eq!(return_types.as_str(), "(iu)");
+
+        let args =
+            get_signature_of_method_args_from_xml(xml_path, "org.example.Void", "DoManyThings")
+                .unwrap();
+        assert_eq!(args.as_str(), "(xy)");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_index_interfaces_in_dir_missing_directory_errors() {
+        let result = index_interfaces_in_dir(Path::new("no-such-xml-dir"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_index_interfaces_in_dir_last_file_wins_on_duplicate_interface() {
+        let dir = std::env::temp_dir().join("zbus_xml_match_test_duplicate_interface");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let xml = r#"<node>
+  <interface name="org.example.Duplicated">
+    <method name="DoThing">
+      <arg name="result" type="s" direction="out"/>
+    </method>
+  </interface>
+</node>"#;
+
+        std::fs::write(dir.join("a_first.xml"), xml).unwrap();
+        std::fs::write(dir.join("b_second.xml"), xml).unwrap();
+
+        let index = index_interfaces_in_dir(&dir).unwrap();
+        assert_eq!(
+            index.get("org.example.Duplicated"),
+            Some(&dir.join("b_second.xml"))
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_path_less_lookup_functions_use_env_var_directory() {
+        std::env::set_var(XML_DIR_ENV_VAR, "xml");
+
+        let signature =
+            get_signal_body_type("org.a11y.atspi.Cache", "AddAccessible", Some("nodeAdded"))
+                .unwrap();
+        assert_eq!(signature, CacheItem::signature());
+
+        let signature = get_method_return_type("org.a11y.atspi.Accessible", "GetRole").unwrap();
+        assert_eq!(signature, Role::signature());
+
+        let signature =
+            get_atspi_event_signature("org.a11y.atspi.Event.Mouse", "Abs").unwrap();
+        assert_signatures_eq!(signature, EventBodyOwned::signature());
+
+        let signature =
+            find_signal_body_type_by_member("AddAccessible", Some("nodeAdded")).unwrap();
+        assert_eq!(signature, CacheItem::signature());
+
+        std::env::remove_var(XML_DIR_ENV_VAR);
+    }
+
+    #[test]
+    fn test_find_signal_body_type_by_member_missing_member_errors() {
+        std::env::set_var(XML_DIR_ENV_VAR, "xml");
+        let result = find_signal_body_type_by_member("NoSuchSignalAnywhere", None);
+        std::env::remove_var(XML_DIR_ENV_VAR);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_get_atspi_mouse_event_signature() {
         let xml = PathBuf::from("xml/Event.xml");
@@ -257,7 +842,7 @@ mod test {
 
         let signature =
             get_signature_of_atspi_event_from_xml(xml, interface_name, member_name).unwrap();
-        assert_eq!(signature, EventBodyOwned::signature());
+        assert_signatures_eq!(signature, EventBodyOwned::signature());
     }
 
     #[test]